@@ -0,0 +1,17 @@
+/// Visibility of an uploaded image.
+pub enum AccessPolicy {
+    /// The image is visible to anyone with the link. (default)
+    Anyone,
+    /// The image is visible only to the uploader.
+    OnlyMe,
+}
+
+impl AccessPolicy {
+    /// Returns the string representation expected by the Gyazo API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccessPolicy::Anyone => "anyone",
+            AccessPolicy::OnlyMe => "only_me",
+        }
+    }
+}
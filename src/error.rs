@@ -0,0 +1,138 @@
+use std::fmt;
+
+/// Errors that can occur when using the Gyazo API client.
+#[derive(Debug)]
+pub enum GyazoError {
+    /// Image data could not be read from disk or from a reader.
+    Io(std::io::Error),
+    /// The HTTP request itself failed (network error, timeout, etc.).
+    Http(reqwest::Error),
+    /// The response body could not be decoded into the expected type.
+    Decode(reqwest::Error),
+    /// The API returned `429 Too Many Requests`.
+    RateLimited {
+        /// When the rate limit is expected to reset, read from the
+        /// `X-RateLimit-Reset` header, if the API reported one.
+        reset_at: Option<u64>,
+    },
+    /// The API returned an error response, or an [`crate::ImageSink`] could
+    /// not complete a publish.
+    Api {
+        /// HTTP status code returned by the API. For a sink failure that no
+        /// server reported (an unparseable timestamp, a response missing a
+        /// field), this is a status chosen by the sink.
+        status: reqwest::StatusCode,
+        /// Error message reported by the API, if any.
+        message: String,
+    },
+}
+
+impl fmt::Display for GyazoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GyazoError::Io(err) => write!(f, "failed to read image data: {err}"),
+            GyazoError::Http(err) => write!(f, "request failed: {err}"),
+            GyazoError::Decode(err) => write!(f, "failed to decode response: {err}"),
+            GyazoError::RateLimited { reset_at: Some(reset_at) } => {
+                write!(f, "rate limited, resets at {reset_at}")
+            }
+            GyazoError::RateLimited { reset_at: None } => write!(f, "rate limited"),
+            GyazoError::Api { status, message } => write!(f, "api error {status}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for GyazoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GyazoError::Io(err) => Some(err),
+            GyazoError::Http(err) | GyazoError::Decode(err) => Some(err),
+            GyazoError::RateLimited { .. } | GyazoError::Api { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for GyazoError {
+    fn from(err: std::io::Error) -> Self {
+        GyazoError::Io(err)
+    }
+}
+
+impl From<reqwest::Error> for GyazoError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_decode() {
+            GyazoError::Decode(err)
+        } else {
+            GyazoError::Http(err)
+        }
+    }
+}
+
+/// Returns the [`GyazoError::RateLimited`] implied by `status` and
+/// `headers`, or `None` if `status` isn't `429 Too Many Requests`.
+///
+/// Split out from [`check_status`] so the 429-vs-other-status branching can
+/// be unit tested without a live response body.
+fn rate_limited_error(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+) -> Option<GyazoError> {
+    if status.as_u16() != 429 {
+        return None;
+    }
+
+    let reset_at = headers
+        .get("x-ratelimit-reset")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    Some(GyazoError::RateLimited { reset_at })
+}
+
+/// Turns a non-success response into the appropriate [`GyazoError`] variant,
+/// distinguishing rate limiting from other API errors.
+pub(crate) async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, GyazoError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    if let Some(err) = rate_limited_error(status, response.headers()) {
+        return Err(err);
+    }
+
+    let message = response.text().await.unwrap_or_default();
+    Err(GyazoError::Api { status, message })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    #[test]
+    fn rate_limited_error_on_429_with_reset() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1700000000"));
+
+        let err = rate_limited_error(reqwest::StatusCode::TOO_MANY_REQUESTS, &headers).unwrap();
+
+        assert!(matches!(
+            err,
+            GyazoError::RateLimited { reset_at: Some(1_700_000_000) }
+        ));
+    }
+
+    #[test]
+    fn rate_limited_error_on_429_without_reset_header() {
+        let err =
+            rate_limited_error(reqwest::StatusCode::TOO_MANY_REQUESTS, &HeaderMap::new()).unwrap();
+
+        assert!(matches!(err, GyazoError::RateLimited { reset_at: None }));
+    }
+
+    #[test]
+    fn no_rate_limited_error_for_other_statuses() {
+        assert!(rate_limited_error(reqwest::StatusCode::NOT_FOUND, &HeaderMap::new()).is_none());
+        assert!(rate_limited_error(reqwest::StatusCode::OK, &HeaderMap::new()).is_none());
+    }
+}
@@ -0,0 +1,135 @@
+use super::error::GyazoError;
+use super::Gyazo;
+
+/// Gyazo API image resource, as returned by the get/list image endpoints.
+#[derive(serde::Deserialize, Debug)]
+pub struct ImageResponse {
+    /// Unique identifier of the image.
+    pub image_id: String,
+    /// Type of the image.
+    pub r#type: String,
+    /// Timestamp when the image was created.
+    pub created_at: String,
+    /// Permalink URL of the image, present unless the image is private.
+    pub permalink_url: Option<String>,
+    /// URL of the thumbnail.
+    pub thumb_url: Option<String>,
+    /// Direct URL to the image.
+    pub url: Option<String>,
+}
+
+/// Response returned when deleting an image.
+#[derive(serde::Deserialize, Debug)]
+pub struct DeleteImageResponse {
+    /// Unique identifier of the deleted image.
+    pub image_id: String,
+    /// Type of the image that was deleted.
+    pub r#type: Option<String>,
+}
+
+/// A page of results from [`Gyazo::list_images`], together with the
+/// pagination metadata reported by the API's response headers.
+#[derive(Debug)]
+pub struct ImageListPage {
+    /// Images contained in this page.
+    pub images: Vec<ImageResponse>,
+    /// Total number of images across all pages (`X-Total-Count`).
+    pub total_count: Option<u32>,
+    /// The page number that was returned (`X-Current-Page`).
+    pub current_page: Option<u32>,
+    /// The number of images requested per page (`X-Per-Page`).
+    pub per_page: Option<u32>,
+}
+
+impl Gyazo {
+    /// Fetches a single image by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_id` - Unique identifier of the image to fetch.
+    pub async fn get_image(&self, image_id: &str) -> Result<ImageResponse, GyazoError> {
+        let response = self
+            .send_with_retry(|| {
+                self.client
+                    .get(format!("https://api.gyazo.com/api/images/{image_id}"))
+                    .query(&[("access_token", &self.access_token)])
+            })
+            .await?;
+
+        Ok(response.json::<ImageResponse>().await?)
+    }
+
+    /// Lists the authenticated user's images, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Page number to fetch, starting at 1. Defaults to 1.
+    /// * `per_page` - Number of images per page. Defaults to the API's own default.
+    ///
+    /// # Returns
+    ///
+    /// An [`ImageListPage`] containing the images along with pagination
+    /// metadata so callers can keep requesting subsequent pages.
+    pub async fn list_images(
+        &self,
+        page: Option<u32>,
+        per_page: Option<u32>,
+    ) -> Result<ImageListPage, GyazoError> {
+        let mut query = vec![("access_token", self.access_token.clone())];
+        if let Some(page) = page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(per_page) = per_page {
+            query.push(("per_page", per_page.to_string()));
+        }
+
+        let response = self
+            .send_with_retry(|| {
+                self.client
+                    .get("https://api.gyazo.com/api/images")
+                    .query(&query)
+            })
+            .await?;
+
+        let total_count = header_as::<u32>(&response, "x-total-count");
+        let current_page = header_as::<u32>(&response, "x-current-page");
+        let per_page = header_as::<u32>(&response, "x-per-page");
+
+        let images = response.json::<Vec<ImageResponse>>().await?;
+
+        Ok(ImageListPage {
+            images,
+            total_count,
+            current_page,
+            per_page,
+        })
+    }
+
+    /// Deletes an image by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_id` - Unique identifier of the image to delete.
+    pub async fn delete_image(
+        &self,
+        image_id: &str,
+    ) -> Result<DeleteImageResponse, GyazoError> {
+        let response = self
+            .send_with_retry(|| {
+                self.client
+                    .delete(format!("https://api.gyazo.com/api/images/{image_id}"))
+                    .query(&[("access_token", &self.access_token)])
+            })
+            .await?;
+
+        Ok(response.json::<DeleteImageResponse>().await?)
+    }
+}
+
+fn header_as<T: std::str::FromStr>(response: &reqwest::Response, name: &str) -> Option<T> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<T>().ok())
+}
@@ -0,0 +1,94 @@
+mod access_policy;
+mod error;
+mod image;
+mod rate_limit;
+mod sink;
+mod upload;
+
+use error::check_status;
+use std::sync::Mutex;
+
+pub use access_policy::AccessPolicy;
+pub use error::GyazoError;
+pub use image::{DeleteImageResponse, ImageListPage, ImageResponse};
+pub use rate_limit::{RateLimit, RetryPolicy};
+pub use sink::{ImageSink, PublishOutcome};
+#[cfg(feature = "bluesky")]
+pub use sink::bluesky::BlueskySink;
+#[cfg(feature = "mastodon")]
+pub use sink::mastodon::MastodonSink;
+pub use upload::{GyazoUploadOptions, GyazoUploadOptionsBuilder, UploadResponse};
+
+/// A client for the Gyazo API.
+pub struct Gyazo {
+    pub(crate) client: reqwest::Client,
+    pub(crate) access_token: String,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    last_rate_limit: Mutex<Option<RateLimit>>,
+}
+
+impl Gyazo {
+    /// Creates a new `Gyazo` client authenticated with the given access token.
+    pub fn new(access_token: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            access_token: access_token.into(),
+            retry_policy: None,
+            last_rate_limit: Mutex::new(None),
+        }
+    }
+
+    /// Opts into automatically retrying requests that are rejected with
+    /// `429 Too Many Requests`, sleeping until the rate limit resets.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Returns the rate limit observed on the most recent request, if any
+    /// request has been made yet.
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.last_rate_limit.lock().unwrap().clone()
+    }
+
+    /// Sends a request built by `build`, recording the latest rate limit
+    /// headers and retrying according to the configured [`RetryPolicy`]
+    /// when the API responds with `429 Too Many Requests`.
+    ///
+    /// `build` is called once per attempt so the request can be rebuilt
+    /// from scratch on retry.
+    pub(crate) async fn send_with_retry<F>(
+        &self,
+        mut build: F,
+    ) -> Result<reqwest::Response, GyazoError>
+    where
+        F: FnMut() -> reqwest::RequestBuilder,
+    {
+        let max_attempts = self
+            .retry_policy
+            .as_ref()
+            .map(|policy| policy.max_attempts)
+            .unwrap_or(1)
+            .max(1);
+
+        for attempt in 1..=max_attempts {
+            let response = build().send().await?;
+            *self.last_rate_limit.lock().unwrap() = Some(RateLimit::from_headers(response.headers()));
+
+            match check_status(response).await {
+                Ok(response) => return Ok(response),
+                Err(GyazoError::RateLimited { reset_at }) if attempt < max_attempts => {
+                    let delay = self
+                        .retry_policy
+                        .as_ref()
+                        .expect("max_attempts > 1 implies a retry policy is set")
+                        .delay_until_reset(reset_at);
+                    tokio::time::sleep(delay).await;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        unreachable!("the loop above always returns before exhausting its attempts")
+    }
+}
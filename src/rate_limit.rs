@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+/// Rate limit information reported by the Gyazo API via the
+/// `X-RateLimit-*` response headers.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimit {
+    /// Maximum number of requests allowed in the current window.
+    pub limit: Option<u32>,
+    /// Number of requests remaining in the current window.
+    pub remaining: Option<u32>,
+    /// Unix timestamp (seconds) when the window resets.
+    pub reset_at: Option<u64>,
+}
+
+impl RateLimit {
+    pub(crate) fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        Self {
+            limit: header_as(headers, "x-ratelimit-limit"),
+            remaining: header_as(headers, "x-ratelimit-remaining"),
+            reset_at: header_as(headers, "x-ratelimit-reset"),
+        }
+    }
+}
+
+fn header_as<T: std::str::FromStr>(headers: &reqwest::header::HeaderMap, name: &str) -> Option<T> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<T>().ok())
+}
+
+/// Opt-in policy for retrying a request after a `429 Too Many Requests`
+/// response, sleeping until the rate limit resets.
+///
+/// # Examples
+///
+/// ```ignore
+/// let gyazo = Gyazo::new(token).with_retry_policy(RetryPolicy::default());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one. A 429 on the
+    /// final attempt is returned to the caller as
+    /// [`crate::GyazoError::RateLimited`].
+    pub max_attempts: u32,
+    /// Upper bound on how long to sleep before retrying, regardless of how
+    /// far away the reported reset time is.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Computes how long to sleep before the next attempt, given the
+    /// `reset_at` reported by the API (if any), capped at `max_backoff`.
+    pub(crate) fn delay_until_reset(&self, reset_at: Option<u64>) -> Duration {
+        let delay = reset_at
+            .and_then(|reset_at| {
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .ok()?
+                    .as_secs();
+                Some(Duration::from_secs(reset_at.saturating_sub(now)))
+            })
+            .unwrap_or(self.max_backoff);
+
+        delay.min(self.max_backoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    fn now_secs() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    #[test]
+    fn from_headers_parses_present_fields() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", HeaderValue::from_static("100"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("42"));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1700000000"));
+
+        let rate_limit = RateLimit::from_headers(&headers);
+
+        assert_eq!(rate_limit.limit, Some(100));
+        assert_eq!(rate_limit.remaining, Some(42));
+        assert_eq!(rate_limit.reset_at, Some(1_700_000_000));
+    }
+
+    #[test]
+    fn from_headers_missing_fields_are_none() {
+        let rate_limit = RateLimit::from_headers(&HeaderMap::new());
+
+        assert_eq!(rate_limit.limit, None);
+        assert_eq!(rate_limit.remaining, None);
+        assert_eq!(rate_limit.reset_at, None);
+    }
+
+    #[test]
+    fn delay_until_reset_is_capped_at_max_backoff() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            max_backoff: Duration::from_secs(10),
+        };
+
+        let far_future_reset = now_secs() + 3600;
+
+        assert_eq!(
+            policy.delay_until_reset(Some(far_future_reset)),
+            Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn delay_until_reset_falls_back_to_max_backoff_when_missing() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            max_backoff: Duration::from_secs(10),
+        };
+
+        assert_eq!(policy.delay_until_reset(None), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn delay_until_reset_is_zero_for_a_reset_in_the_past() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            max_backoff: Duration::from_secs(10),
+        };
+
+        let past_reset = now_secs().saturating_sub(100);
+
+        assert_eq!(policy.delay_until_reset(Some(past_reset)), Duration::ZERO);
+    }
+}
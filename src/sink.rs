@@ -0,0 +1,112 @@
+use super::error::GyazoError;
+use super::upload::{GyazoUploadOptions, UploadResponse};
+use super::Gyazo;
+use std::fs;
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+
+#[cfg(feature = "bluesky")]
+pub mod bluesky;
+#[cfg(feature = "mastodon")]
+pub mod mastodon;
+
+/// A social feed that a freshly uploaded image can be cross-posted to.
+///
+/// Implementations are behind feature flags (`bluesky`, `mastodon`); see
+/// [`bluesky::BlueskySink`] and [`mastodon::MastodonSink`].
+pub trait ImageSink {
+    /// Publishes `image` (the same bytes that were uploaded to Gyazo) to
+    /// this sink, using the Gyazo `resp` and an optional `alt` text.
+    ///
+    /// Returns a boxed future rather than being an `async fn` so `ImageSink`
+    /// stays object-safe and can be used as `&dyn ImageSink`.
+    fn publish<'a>(
+        &'a self,
+        resp: &'a UploadResponse,
+        image: &'a [u8],
+        alt: Option<&'a str>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), GyazoError>> + Send + 'a>>;
+}
+
+#[cfg(any(feature = "bluesky", feature = "mastodon"))]
+pub(crate) fn mime_from_url(url: &str) -> &'static str {
+    match url.rsplit('.').next().unwrap_or("") {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Result of [`Gyazo::upload_and_publish`]: the successful Gyazo upload,
+/// plus the outcome of publishing to each configured sink, in the same
+/// order as the `sinks` slice that was passed in.
+pub struct PublishOutcome {
+    /// The uploaded image, as returned by Gyazo.
+    pub upload: UploadResponse,
+    /// Per-sink publish results. A failure here does not mean the upload
+    /// itself failed — `upload` is always present on success.
+    pub sink_results: Vec<Result<(), GyazoError>>,
+}
+
+impl Gyazo {
+    /// Uploads an image file and fans it out to the given [`ImageSink`]s.
+    ///
+    /// The alt text passed to each sink is taken from `options.desc`,
+    /// falling back to `options.title`. Every sink is attempted even if an
+    /// earlier one fails, so one dead sink doesn't suppress the rest; check
+    /// [`PublishOutcome::sink_results`] to see which ones succeeded.
+    pub async fn upload_and_publish<P: AsRef<Path>>(
+        &self,
+        image_path: P,
+        options: Option<&GyazoUploadOptions>,
+        sinks: &[&dyn ImageSink],
+    ) -> Result<PublishOutcome, GyazoError> {
+        let data = fs::read(&image_path)?;
+        let file_name = image_path
+            .as_ref()
+            .to_str()
+            .unwrap_or("image")
+            .to_string();
+
+        let upload = self.upload_bytes(data.clone(), &file_name, options).await?;
+
+        let alt = options.and_then(|opts| opts.desc.as_deref().or(opts.title.as_deref()));
+        let mut sink_results = Vec::with_capacity(sinks.len());
+        for sink in sinks {
+            sink_results.push(sink.publish(&upload, &data, alt).await);
+        }
+
+        Ok(PublishOutcome {
+            upload,
+            sink_results,
+        })
+    }
+}
+
+#[cfg(all(test, any(feature = "bluesky", feature = "mastodon")))]
+mod tests {
+    use super::mime_from_url;
+
+    #[test]
+    fn maps_known_extensions() {
+        assert_eq!(mime_from_url("https://i.gyazo.com/abc.png"), "image/png");
+        assert_eq!(mime_from_url("https://i.gyazo.com/abc.jpeg"), "image/jpeg");
+        assert_eq!(mime_from_url("https://i.gyazo.com/abc.gif"), "image/gif");
+        assert_eq!(mime_from_url("https://i.gyazo.com/abc.webp"), "image/webp");
+    }
+
+    #[test]
+    fn falls_back_to_octet_stream_for_unknown_or_missing_extensions() {
+        assert_eq!(
+            mime_from_url("https://i.gyazo.com/abc.bmp"),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            mime_from_url("https://i.gyazo.com/abc"),
+            "application/octet-stream"
+        );
+    }
+}
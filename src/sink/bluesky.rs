@@ -0,0 +1,135 @@
+use super::{mime_from_url, ImageSink};
+use crate::error::check_status;
+use crate::{GyazoError, UploadResponse};
+use std::future::Future;
+use std::pin::Pin;
+
+/// Publishes uploaded images to Bluesky as an `app.bsky.embed.images` post.
+pub struct BlueskySink {
+    client: reqwest::Client,
+    pds_host: String,
+    access_jwt: String,
+    did: String,
+}
+
+impl BlueskySink {
+    /// Creates a sink that publishes to `pds_host`, authenticated with an
+    /// existing session's access JWT for the repo identified by `did`.
+    pub fn new(
+        pds_host: impl Into<String>,
+        access_jwt: impl Into<String>,
+        did: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            pds_host: pds_host.into(),
+            access_jwt: access_jwt.into(),
+            did: did.into(),
+        }
+    }
+}
+
+impl ImageSink for BlueskySink {
+    fn publish<'a>(
+        &'a self,
+        resp: &'a UploadResponse,
+        image: &'a [u8],
+        alt: Option<&'a str>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), GyazoError>> + Send + 'a>> {
+        Box::pin(async move {
+            let mime = mime_from_url(&resp.url);
+
+            let created_at = gyazo_timestamp_to_rfc3339(&resp.created_at).ok_or_else(|| {
+                GyazoError::Api {
+                    status: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: format!(
+                        "could not convert gyazo created_at {:?} to rfc3339",
+                        resp.created_at
+                    ),
+                }
+            })?;
+
+            let response = self
+                .client
+                .post(format!("{}/xrpc/com.atproto.repo.uploadBlob", self.pds_host))
+                .bearer_auth(&self.access_jwt)
+                .header("content-type", mime)
+                .body(image.to_vec())
+                .send()
+                .await?;
+            let blob: serde_json::Value = check_status(response).await?.json().await?;
+
+            let record = serde_json::json!({
+                "collection": "app.bsky.feed.post",
+                "repo": self.did,
+                "record": {
+                    "$type": "app.bsky.feed.post",
+                    "text": "",
+                    "createdAt": created_at,
+                    "embed": {
+                        "$type": "app.bsky.embed.images",
+                        "images": [{
+                            "image": blob["blob"],
+                            "alt": alt.unwrap_or_default(),
+                        }],
+                    },
+                },
+            });
+
+            let response = self
+                .client
+                .post(format!("{}/xrpc/com.atproto.repo.createRecord", self.pds_host))
+                .bearer_auth(&self.access_jwt)
+                .json(&record)
+                .send()
+                .await?;
+            check_status(response).await?;
+
+            Ok(())
+        })
+    }
+}
+
+/// Converts Gyazo's `created_at` format (`"YYYY-MM-DD HH:MM:SS +HHMM"`, a
+/// space-separated date/time with a colonless offset) into the RFC3339
+/// string (`"YYYY-MM-DDTHH:MM:SS+HH:MM"`) required by the
+/// `app.bsky.feed.post` lexicon.
+fn gyazo_timestamp_to_rfc3339(raw: &str) -> Option<String> {
+    let (date, rest) = raw.split_once(' ')?;
+    let (time, offset) = rest.split_once(' ')?;
+
+    if offset.len() != 5 {
+        return None;
+    }
+    let (sign, digits) = offset.split_at(1);
+    if !matches!(sign, "+" | "-") || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (offset_hours, offset_minutes) = digits.split_at(2);
+
+    Some(format!("{date}T{time}{sign}{offset_hours}:{offset_minutes}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gyazo_timestamp_to_rfc3339;
+
+    #[test]
+    fn converts_gyazo_timestamp_to_rfc3339() {
+        assert_eq!(
+            gyazo_timestamp_to_rfc3339("2014-05-21 14:22:01 +0000"),
+            Some("2014-05-21T14:22:01+00:00".to_string())
+        );
+        assert_eq!(
+            gyazo_timestamp_to_rfc3339("2014-05-21 14:22:01 -0700"),
+            Some("2014-05-21T14:22:01-07:00".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_unparseable_timestamps() {
+        assert_eq!(gyazo_timestamp_to_rfc3339(""), None);
+        assert_eq!(gyazo_timestamp_to_rfc3339("2014-05-21T14:22:01Z"), None);
+        assert_eq!(gyazo_timestamp_to_rfc3339("2014-05-21 14:22:01 garbage"), None);
+    }
+}
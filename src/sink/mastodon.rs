@@ -0,0 +1,75 @@
+use super::{mime_from_url, ImageSink};
+use crate::error::check_status;
+use crate::{GyazoError, UploadResponse};
+use reqwest::multipart;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Publishes uploaded images to a Mastodon-compatible instance as a status
+/// with attached media.
+pub struct MastodonSink {
+    client: reqwest::Client,
+    instance_url: String,
+    access_token: String,
+}
+
+impl MastodonSink {
+    /// Creates a sink that publishes to `instance_url` (e.g.
+    /// `https://mastodon.social`), authenticated with `access_token`.
+    pub fn new(instance_url: impl Into<String>, access_token: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            instance_url: instance_url.into(),
+            access_token: access_token.into(),
+        }
+    }
+}
+
+impl ImageSink for MastodonSink {
+    fn publish<'a>(
+        &'a self,
+        resp: &'a UploadResponse,
+        image: &'a [u8],
+        alt: Option<&'a str>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), GyazoError>> + Send + 'a>> {
+        Box::pin(async move {
+            let mime = mime_from_url(&resp.url);
+
+            let mut part = multipart::Part::bytes(image.to_vec()).file_name("image");
+            part = part.mime_str(mime).map_err(|err| GyazoError::Api {
+                status: reqwest::StatusCode::BAD_REQUEST,
+                message: err.to_string(),
+            })?;
+
+            let mut form = multipart::Form::new().part("file", part);
+            if let Some(alt) = alt {
+                form = form.text("description", alt.to_string());
+            }
+
+            let response = self
+                .client
+                .post(format!("{}/api/v2/media", self.instance_url))
+                .bearer_auth(&self.access_token)
+                .multipart(form)
+                .send()
+                .await?;
+            let media: serde_json::Value = check_status(response).await?.json().await?;
+
+            let media_id = media["id"].as_str().ok_or_else(|| GyazoError::Api {
+                status: reqwest::StatusCode::BAD_GATEWAY,
+                message: "mastodon media upload response is missing \"id\"".into(),
+            })?;
+
+            let response = self
+                .client
+                .post(format!("{}/api/v1/statuses", self.instance_url))
+                .bearer_auth(&self.access_token)
+                .form(&[("status", ""), ("media_ids[]", media_id)])
+                .send()
+                .await?;
+            check_status(response).await?;
+
+            Ok(())
+        })
+    }
+}
@@ -1,4 +1,5 @@
 use super::access_policy::AccessPolicy;
+use super::error::GyazoError;
 use super::Gyazo;
 use reqwest::multipart;
 use std::fs;
@@ -57,8 +58,78 @@ pub struct GyazoUploadOptions {
     pub collection_id: Option<String>,
 }
 
+impl GyazoUploadOptions {
+    /// Starts building a `GyazoUploadOptions` via chainable setters.
+    pub fn builder() -> GyazoUploadOptionsBuilder {
+        GyazoUploadOptionsBuilder::default()
+    }
+}
+
+/// Builder for [`GyazoUploadOptions`].
+///
+/// Constructed via [`GyazoUploadOptions::builder`].
+#[derive(Default)]
+pub struct GyazoUploadOptionsBuilder {
+    options: GyazoUploadOptions,
+}
+
+impl GyazoUploadOptionsBuilder {
+    /// Sets [`GyazoUploadOptions::access_policy`].
+    pub fn access_policy(mut self, access_policy: AccessPolicy) -> Self {
+        self.options.access_policy = Some(access_policy);
+        self
+    }
+
+    /// Sets [`GyazoUploadOptions::metadata_is_public`].
+    pub fn metadata_is_public(mut self, metadata_is_public: bool) -> Self {
+        self.options.metadata_is_public = Some(metadata_is_public);
+        self
+    }
+
+    /// Sets [`GyazoUploadOptions::referer_url`].
+    pub fn referer_url(mut self, referer_url: impl Into<String>) -> Self {
+        self.options.referer_url = Some(referer_url.into());
+        self
+    }
+
+    /// Sets [`GyazoUploadOptions::app`].
+    pub fn app(mut self, app: impl Into<String>) -> Self {
+        self.options.app = Some(app.into());
+        self
+    }
+
+    /// Sets [`GyazoUploadOptions::title`].
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.options.title = Some(title.into());
+        self
+    }
+
+    /// Sets [`GyazoUploadOptions::desc`].
+    pub fn desc(mut self, desc: impl Into<String>) -> Self {
+        self.options.desc = Some(desc.into());
+        self
+    }
+
+    /// Sets [`GyazoUploadOptions::created_at`].
+    pub fn created_at(mut self, created_at: f64) -> Self {
+        self.options.created_at = Some(created_at);
+        self
+    }
+
+    /// Sets [`GyazoUploadOptions::collection_id`].
+    pub fn collection_id(mut self, collection_id: impl Into<String>) -> Self {
+        self.options.collection_id = Some(collection_id.into());
+        self
+    }
+
+    /// Builds the configured [`GyazoUploadOptions`].
+    pub fn build(self) -> GyazoUploadOptions {
+        self.options
+    }
+}
+
 impl Gyazo {
-    /// Uploads an image to Gyazo.
+    /// Uploads an image file to Gyazo.
     ///
     /// # Arguments
     ///
@@ -67,60 +138,107 @@ impl Gyazo {
     ///
     /// # Returns
     ///
-    /// A `Result` containing `UploadResponse` on success or a `reqwest::Error` on failure.
-    // TODO: test
+    /// A `Result` containing `UploadResponse` on success, or a `GyazoError`
+    /// if the file could not be read or the request failed.
     pub async fn upload<P: AsRef<Path>>(
         &self,
         image_path: P,
         options: Option<&GyazoUploadOptions>,
-    ) -> Result<UploadResponse, reqwest::Error> {
-        let file_content = fs::read(&image_path).expect("Failed to read the file");
-
-        let mut form = multipart::Form::new()
-            .text("access_token", self.access_token.clone())
-            .part(
-                "imagedata",
-                multipart::Part::bytes(file_content)
-                    .file_name(image_path.as_ref().to_str().unwrap().to_string()),
-            );
-
-        if let Some(opts) = options {
-            if let Some(access_policy) = &opts.access_policy {
-                form = form.text("access_policy", access_policy.as_str().to_string());
-            }
-            if let Some(metadata_is_public) = &opts.metadata_is_public {
-                form = form.text("metadata_is_public", metadata_is_public.to_string());
-            }
-            if let Some(referer_url) = &opts.referer_url {
-                form = form.text("referer_url", referer_url.clone());
-            }
-            if let Some(app) = &opts.app {
-                form = form.text("app", app.clone());
-            }
-            if let Some(title) = &opts.title {
-                form = form.text("title", title.clone());
-            }
-            if let Some(desc) = &opts.desc {
-                form = form.text("desc", desc.clone());
-            }
-            if let Some(created_at) = opts.created_at {
-                form = form.text("created_at", created_at.to_string());
-            }
-            if let Some(collection_id) = &opts.collection_id {
-                form = form.text("collection_id", collection_id.clone());
+    ) -> Result<UploadResponse, GyazoError> {
+        let file_content = fs::read(&image_path)?;
+        let file_name = image_path
+            .as_ref()
+            .to_str()
+            .unwrap_or("image")
+            .to_string();
+
+        self.upload_bytes(file_content, &file_name, options).await
+    }
+
+    /// Uploads in-memory image data to Gyazo, without touching disk.
+    ///
+    /// Useful for screenshots, decoded frames, or downloaded buffers that
+    /// were never written to a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Raw bytes of the image to upload.
+    /// * `file_name` - File name to report to the Gyazo API (e.g. `"screenshot.png"`).
+    /// * `options` - Optional upload configuration.
+    pub async fn upload_bytes(
+        &self,
+        data: Vec<u8>,
+        file_name: &str,
+        options: Option<&GyazoUploadOptions>,
+    ) -> Result<UploadResponse, GyazoError> {
+        let build_form = || {
+            let mut form = multipart::Form::new()
+                .text("access_token", self.access_token.clone())
+                .part(
+                    "imagedata",
+                    multipart::Part::bytes(data.clone()).file_name(file_name.to_string()),
+                );
+
+            if let Some(opts) = options {
+                if let Some(access_policy) = &opts.access_policy {
+                    form = form.text("access_policy", access_policy.as_str().to_string());
+                }
+                if let Some(metadata_is_public) = &opts.metadata_is_public {
+                    form = form.text("metadata_is_public", metadata_is_public.to_string());
+                }
+                if let Some(referer_url) = &opts.referer_url {
+                    form = form.text("referer_url", referer_url.clone());
+                }
+                if let Some(app) = &opts.app {
+                    form = form.text("app", app.clone());
+                }
+                if let Some(title) = &opts.title {
+                    form = form.text("title", title.clone());
+                }
+                if let Some(desc) = &opts.desc {
+                    form = form.text("desc", desc.clone());
+                }
+                if let Some(created_at) = opts.created_at {
+                    form = form.text("created_at", created_at.to_string());
+                }
+                if let Some(collection_id) = &opts.collection_id {
+                    form = form.text("collection_id", collection_id.clone());
+                }
             }
-        }
+
+            form
+        };
 
         let response = self
-            .client
-            .post("https://upload.gyazo.com/api/upload")
-            .multipart(form)
-            .send()
-            .await?
-            .error_for_status()?
-            .json::<UploadResponse>()
+            .send_with_retry(|| {
+                self.client
+                    .post("https://upload.gyazo.com/api/upload")
+                    .multipart(build_form())
+            })
             .await?;
 
-        Ok(response)
+        Ok(response.json::<UploadResponse>().await?)
+    }
+
+    /// Uploads image data read from an async reader, such as a network
+    /// stream or an in-memory cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Source of the image bytes.
+    /// * `file_name` - File name to report to the Gyazo API.
+    /// * `options` - Optional upload configuration.
+    pub async fn upload_reader<R: tokio::io::AsyncRead + Unpin>(
+        &self,
+        mut reader: R,
+        file_name: &str,
+        options: Option<&GyazoUploadOptions>,
+    ) -> Result<UploadResponse, GyazoError> {
+        use tokio::io::AsyncReadExt;
+
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).await?;
+
+        self.upload_bytes(data, file_name, options).await
     }
 }